@@ -1,7 +1,13 @@
 use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use hmac::{Hmac, Mac};
+use k256::ecdsa::signature::{Signer as _, Verifier as _};
 use rand::rngs::OsRng;
+use sha2::Sha512;
 use std::sync::Arc;
 use thiserror::Error;
+use zeroize::Zeroize;
+
+type HmacSha512 = Hmac<Sha512>;
 
 // Error types for proper error handling
 #[derive(Error, Debug, uniffi::Error)]
@@ -16,6 +22,41 @@ pub enum Ed25519Error {
     SigningFailed { reason: String },
     #[error("Verification failed: {reason}")]
     VerificationFailed { reason: String },
+    #[error("Base58 decode failed: {reason}")]
+    Base58DecodeFailed { reason: String },
+    #[error("Keypair file I/O failed: {reason}")]
+    KeypairFileError { reason: String },
+    #[error("Unsupported key type: {reason}")]
+    UnsupportedKeyType { reason: String },
+}
+
+/// The signature scheme a key or signature was produced with. Public keys
+/// can carry it as a one-byte tag (`Ed25519` = 0, `Secp256k1` = 1, see
+/// `TypedKeyPair::tagged_public_key`) so archives that mix schemes can tell
+/// keys apart; signatures carry no tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum KeyType {
+    Ed25519,
+    Secp256k1,
+}
+
+impl KeyType {
+    fn tag(self) -> u8 {
+        match self {
+            KeyType::Ed25519 => 0,
+            KeyType::Secp256k1 => 1,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self, Ed25519Error> {
+        match tag {
+            0 => Ok(KeyType::Ed25519),
+            1 => Ok(KeyType::Secp256k1),
+            other => Err(Ed25519Error::UnsupportedKeyType {
+                reason: format!("Unknown key type tag: {}", other),
+            }),
+        }
+    }
 }
 
 // Key pair structure
@@ -41,19 +82,45 @@ impl Ed25519KeyPair {
         self.public_key.clone()
     }
 
-    /// Get the private key bytes
-    pub fn get_private_key(&self) -> Vec<u8> {
-        self.private_key.clone()
-    }
-
     /// Get the public key as a hex string
     pub fn get_public_key_hex(&self) -> String {
         hex::encode(&self.public_key)
     }
 
-    /// Get the private key as a hex string
-    pub fn get_private_key_hex(&self) -> String {
-        hex::encode(&self.private_key)
+    /// Encode the keypair as a base58 string of `private_key || public_key`,
+    /// matching the Solana CLI keypair convention.
+    pub fn to_base58_string(&self) -> String {
+        let mut bytes = self.private_key.clone();
+        bytes.extend_from_slice(&self.public_key);
+        bs58::encode(bytes).into_string()
+    }
+
+    /// Decode a keypair from the base58 `private_key || public_key` format
+    /// produced by `to_base58_string`.
+    #[uniffi::constructor]
+    pub fn from_base58_string(s: String) -> Result<Arc<Self>, Ed25519Error> {
+        let bytes = bs58::decode(&s)
+            .into_vec()
+            .map_err(|e| Ed25519Error::Base58DecodeFailed {
+                reason: e.to_string(),
+            })?;
+
+        if bytes.len() != 64 {
+            return Err(Ed25519Error::Base58DecodeFailed {
+                reason: format!("Decoded keypair must be exactly 64 bytes, got {}", bytes.len()),
+            });
+        }
+
+        Ok(Arc::new(Self {
+            private_key: bytes[..32].to_vec(),
+            public_key: bytes[32..].to_vec(),
+        }))
+    }
+}
+
+impl Drop for Ed25519KeyPair {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
     }
 }
 
@@ -65,13 +132,15 @@ pub fn generate_keypair() -> Arc<Ed25519KeyPair> {
     let mut rng = OsRng;
     let mut secret_key_bytes = [0u8; 32];
     rng.fill_bytes(&mut secret_key_bytes);
-    
+
     let signing_key = SigningKey::from_bytes(&secret_key_bytes);
     let verifying_key = signing_key.verifying_key();
-    
+
     let private_key = secret_key_bytes.to_vec();
     let public_key = verifying_key.to_bytes().to_vec();
-    
+
+    secret_key_bytes.zeroize();
+
     Arc::new(Ed25519KeyPair {
         public_key,
         private_key,
@@ -80,19 +149,22 @@ pub fn generate_keypair() -> Arc<Ed25519KeyPair> {
 
 /// Sign a message with the given private key
 #[uniffi::export]
-pub fn sign_message(message: Vec<u8>, private_key: Vec<u8>) -> Result<Vec<u8>, Ed25519Error> {
+pub fn sign_message(message: Vec<u8>, mut private_key: Vec<u8>) -> Result<Vec<u8>, Ed25519Error> {
     if private_key.len() != 32 {
-        return Err(Ed25519Error::InvalidPrivateKey {
-            reason: format!("Private key must be exactly 32 bytes, got {}", private_key.len()),
-        });
+        let reason = format!("Private key must be exactly 32 bytes, got {}", private_key.len());
+        private_key.zeroize();
+        return Err(Ed25519Error::InvalidPrivateKey { reason });
     }
 
     let mut key_bytes = [0u8; 32];
     key_bytes.copy_from_slice(&private_key);
-    
+
     let signing_key = SigningKey::from_bytes(&key_bytes);
     let signature = signing_key.sign(&message);
-    
+
+    key_bytes.zeroize();
+    private_key.zeroize();
+
     Ok(signature.to_bytes().to_vec())
 }
 
@@ -134,6 +206,220 @@ pub fn verify_signature(
     }
 }
 
+/// A key pair tagged with the signature scheme it was generated for, so
+/// callers working across ed25519 and secp256k1 archives can handle both
+/// through one type instead of juggling scheme-specific key pair structs.
+#[derive(uniffi::Object)]
+pub struct TypedKeyPair {
+    pub key_type: KeyType,
+    pub public_key: Vec<u8>,
+    pub private_key: Vec<u8>,
+}
+
+#[uniffi::export]
+impl TypedKeyPair {
+    /// Get the key type this pair was generated for
+    pub fn get_key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    /// Get the public key bytes
+    pub fn get_public_key(&self) -> Vec<u8> {
+        self.public_key.clone()
+    }
+
+    /// Get the public key prefixed with its one-byte key type tag, for
+    /// storage in archives that mix ed25519 and secp256k1 keys.
+    pub fn tagged_public_key(&self) -> Vec<u8> {
+        let mut tagged = vec![self.key_type.tag()];
+        tagged.extend_from_slice(&self.public_key);
+        tagged
+    }
+}
+
+impl Drop for TypedKeyPair {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Generate a new key pair for the given signature scheme.
+#[uniffi::export]
+pub fn generate_keypair_typed(key_type: KeyType) -> Arc<TypedKeyPair> {
+    match key_type {
+        KeyType::Ed25519 => {
+            let keypair = generate_keypair();
+            Arc::new(TypedKeyPair {
+                key_type,
+                public_key: keypair.public_key.clone(),
+                private_key: keypair.private_key.clone(),
+            })
+        }
+        KeyType::Secp256k1 => {
+            let signing_key = k256::ecdsa::SigningKey::random(&mut OsRng);
+            let verifying_key = k256::ecdsa::VerifyingKey::from(&signing_key);
+
+            let private_key = signing_key.to_bytes().to_vec();
+            let public_key = verifying_key.to_encoded_point(true).as_bytes().to_vec();
+
+            Arc::new(TypedKeyPair {
+                key_type,
+                public_key,
+                private_key,
+            })
+        }
+    }
+}
+
+/// Sign a message using the given key type's backend (ed25519-dalek or
+/// secp256k1 ECDSA).
+#[uniffi::export]
+pub fn sign_message_typed(
+    message: Vec<u8>,
+    mut private_key: Vec<u8>,
+    key_type: KeyType,
+) -> Result<Vec<u8>, Ed25519Error> {
+    match key_type {
+        KeyType::Ed25519 => sign_message(message, private_key),
+        KeyType::Secp256k1 => {
+            let signing_key = match k256::ecdsa::SigningKey::from_slice(&private_key) {
+                Ok(signing_key) => signing_key,
+                Err(e) => {
+                    private_key.zeroize();
+                    return Err(Ed25519Error::InvalidPrivateKey {
+                        reason: e.to_string(),
+                    });
+                }
+            };
+
+            let signature: k256::ecdsa::Signature = signing_key.sign(&message);
+
+            private_key.zeroize();
+
+            Ok(signature.to_bytes().to_vec())
+        }
+    }
+}
+
+/// Verify a signature using the given key type's backend (ed25519-dalek or
+/// secp256k1 ECDSA).
+#[uniffi::export]
+pub fn verify_signature_typed(
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    public_key: Vec<u8>,
+    key_type: KeyType,
+) -> Result<bool, Ed25519Error> {
+    match key_type {
+        KeyType::Ed25519 => verify_signature(message, signature, public_key),
+        KeyType::Secp256k1 => {
+            let verifying_key =
+                k256::ecdsa::VerifyingKey::from_sec1_bytes(&public_key).map_err(|e| {
+                    Ed25519Error::InvalidPublicKey {
+                        reason: e.to_string(),
+                    }
+                })?;
+
+            let signature =
+                k256::ecdsa::Signature::from_slice(&signature).map_err(|e| {
+                    Ed25519Error::InvalidSignature {
+                        reason: e.to_string(),
+                    }
+                })?;
+
+            Ok(verifying_key.verify(&message, &signature).is_ok())
+        }
+    }
+}
+
+/// Verify a signature against a public key tagged with its key type, as
+/// produced by `TypedKeyPair::tagged_public_key`. Lets callers handling
+/// mixed-scheme archives dispatch to the right backend from the tag alone,
+/// instead of tracking each key's `KeyType` separately.
+#[uniffi::export]
+pub fn verify_signature_tagged(
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    tagged_public_key: Vec<u8>,
+) -> Result<bool, Ed25519Error> {
+    let (tag, public_key) = tagged_public_key.split_first().ok_or_else(|| {
+        Ed25519Error::UnsupportedKeyType {
+            reason: "Tagged public key must contain at least a one-byte type tag".to_string(),
+        }
+    })?;
+
+    let key_type = KeyType::from_tag(*tag)?;
+    verify_signature_typed(message, signature, public_key.to_vec(), key_type)
+}
+
+/// Verify many (message, signature, public key) triples at once.
+///
+/// Uses ed25519-dalek's batch verification, which checks a random linear
+/// combination of the individual verification equations in a single
+/// multiscalar multiplication instead of one scalar multiplication per
+/// signature. This is much faster than looping over `verify_signature` when
+/// verifying large signed logs or archives, at the cost of not identifying
+/// which signature failed if the batch as a whole is invalid -- callers that
+/// need to isolate a bad signature should fall back to `verify_signature`
+/// per item.
+#[uniffi::export]
+pub fn verify_batch(
+    messages: Vec<Vec<u8>>,
+    signatures: Vec<Vec<u8>>,
+    public_keys: Vec<Vec<u8>>,
+) -> Result<bool, Ed25519Error> {
+    if messages.len() != signatures.len() || messages.len() != public_keys.len() {
+        return Err(Ed25519Error::VerificationFailed {
+            reason: format!(
+                "messages, signatures, and public_keys must have equal length, got {}, {}, {}",
+                messages.len(),
+                signatures.len(),
+                public_keys.len()
+            ),
+        });
+    }
+
+    let signatures = signatures
+        .iter()
+        .map(|signature| {
+            let sig_bytes: [u8; 64] =
+                signature
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Ed25519Error::InvalidSignature {
+                        reason: format!(
+                            "Signature must be exactly 64 bytes, got {}",
+                            signature.len()
+                        ),
+                    })?;
+            Ok(Signature::from_bytes(&sig_bytes))
+        })
+        .collect::<Result<Vec<_>, Ed25519Error>>()?;
+
+    let verifying_keys = public_keys
+        .iter()
+        .map(|public_key| {
+            let key_bytes: [u8; 32] =
+                public_key
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Ed25519Error::InvalidPublicKey {
+                        reason: format!(
+                            "Public key must be exactly 32 bytes, got {}",
+                            public_key.len()
+                        ),
+                    })?;
+            VerifyingKey::from_bytes(&key_bytes).map_err(|e| Ed25519Error::InvalidPublicKey {
+                reason: e.to_string(),
+            })
+        })
+        .collect::<Result<Vec<_>, Ed25519Error>>()?;
+
+    let message_slices: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+
+    Ok(ed25519_dalek::verify_batch(&message_slices, &signatures, &verifying_keys).is_ok())
+}
+
 /// Create a keypair from existing private key bytes
 #[uniffi::export]
 pub fn keypair_from_private_key(private_key: Vec<u8>) -> Result<Arc<Ed25519KeyPair>, Ed25519Error> {
@@ -145,17 +431,525 @@ pub fn keypair_from_private_key(private_key: Vec<u8>) -> Result<Arc<Ed25519KeyPa
 
     let mut key_bytes = [0u8; 32];
     key_bytes.copy_from_slice(&private_key);
-    
+
     let signing_key = SigningKey::from_bytes(&key_bytes);
     let verifying_key = signing_key.verifying_key();
-    
+
     let public_key = verifying_key.to_bytes().to_vec();
-    
+
+    key_bytes.zeroize();
+
     Ok(Arc::new(Ed25519KeyPair {
         public_key,
         private_key,
     }))
 }
 
+/// Parse a `m/44'/501'/0'/0'` style path into its child indices, treating
+/// every segment as hardened regardless of whether it carries a trailing `'`.
+fn parse_derivation_path(path: &str) -> Result<Vec<u32>, Ed25519Error> {
+    let mut segments = path.split('/');
+
+    match segments.next() {
+        Some("m") => {}
+        _ => {
+            return Err(Ed25519Error::InvalidPrivateKey {
+                reason: format!("Derivation path must start with \"m\", got \"{}\"", path),
+            })
+        }
+    }
+
+    segments
+        .map(|segment| {
+            let index_str = segment.strip_suffix('\'').unwrap_or(segment);
+            index_str
+                .parse::<u32>()
+                .map(|index| index | 0x8000_0000)
+                .map_err(|_| Ed25519Error::InvalidPrivateKey {
+                    reason: format!("Invalid derivation path segment: \"{}\"", segment),
+                })
+        })
+        .collect()
+}
+
+/// Derive an ed25519 key pair from a seed and a SLIP-0010 derivation path.
+///
+/// All path segments are treated as hardened, matching SLIP-0010's ed25519
+/// rules (ed25519 has no defined public-key derivation, so every level must
+/// be hardened).
+#[uniffi::export]
+pub fn derive_keypair_from_seed(
+    seed: Vec<u8>,
+    path: String,
+) -> Result<Arc<Ed25519KeyPair>, Ed25519Error> {
+    if seed.len() < 16 {
+        return Err(Ed25519Error::InvalidPrivateKey {
+            reason: format!("Seed must be at least 16 bytes, got {}", seed.len()),
+        });
+    }
+
+    let indices = parse_derivation_path(&path)?;
+
+    let mut mac = HmacSha512::new_from_slice(b"ed25519 seed")
+        .expect("HMAC can take a key of any size");
+    mac.update(&seed);
+    let i = mac.finalize().into_bytes();
+    let (mut key, mut chain_code) = (i[..32].to_vec(), i[32..].to_vec());
+
+    for index in indices {
+        let mut mac = HmacSha512::new_from_slice(&chain_code)
+            .expect("HMAC can take a key of any size");
+        mac.update(&[0u8]);
+        mac.update(&key);
+        mac.update(&index.to_be_bytes());
+        let i = mac.finalize().into_bytes();
+        key = i[..32].to_vec();
+        chain_code = i[32..].to_vec();
+    }
+
+    keypair_from_private_key(key)
+}
+
+/// Write a keypair to disk as the JSON byte-array format (`[12,34,...]`)
+/// used by the Solana CLI and similar tooling, so keys round-trip with
+/// existing key stores.
+#[uniffi::export]
+pub fn write_keypair_file(keypair: Arc<Ed25519KeyPair>, path: String) -> Result<(), Ed25519Error> {
+    let mut bytes = keypair.private_key.clone();
+    bytes.extend_from_slice(&keypair.public_key);
+
+    let json = serde_json::to_string(&bytes).map_err(|e| Ed25519Error::KeypairFileError {
+        reason: e.to_string(),
+    })?;
+
+    std::fs::write(&path, json).map_err(|e| Ed25519Error::KeypairFileError {
+        reason: format!("Failed to write \"{}\": {}", path, e),
+    })
+}
+
+/// Read a keypair previously written by `write_keypair_file`.
+#[uniffi::export]
+pub fn read_keypair_file(path: String) -> Result<Arc<Ed25519KeyPair>, Ed25519Error> {
+    let contents = std::fs::read_to_string(&path).map_err(|e| Ed25519Error::KeypairFileError {
+        reason: format!("Failed to read \"{}\": {}", path, e),
+    })?;
+
+    let bytes: Vec<u8> =
+        serde_json::from_str(&contents).map_err(|e| Ed25519Error::KeypairFileError {
+            reason: e.to_string(),
+        })?;
+
+    if bytes.len() != 64 {
+        return Err(Ed25519Error::KeypairFileError {
+            reason: format!("Keypair file must contain exactly 64 bytes, got {}", bytes.len()),
+        });
+    }
+
+    Ok(Arc::new(Ed25519KeyPair {
+        private_key: bytes[..32].to_vec(),
+        public_key: bytes[32..].to_vec(),
+    }))
+}
+
+/// Prepend a length-prefixed domain-separation tag to a payload, so the same
+/// bytes signed under different domains (e.g. `"manifest-v1"` vs.
+/// `"entry-v1"`) never collide. The tag is `len(domain) as u32 BE || domain
+/// || payload`.
+fn domain_separated_message(payload: &[u8], domain: &str) -> Vec<u8> {
+    let domain_bytes = domain.as_bytes();
+    let mut message = Vec::with_capacity(4 + domain_bytes.len() + payload.len());
+    message.extend_from_slice(&(domain_bytes.len() as u32).to_be_bytes());
+    message.extend_from_slice(domain_bytes);
+    message.extend_from_slice(payload);
+    message
+}
+
+/// Sign a structured document within a given domain, preventing the
+/// resulting signature from being replayed against a different domain or as
+/// a raw `sign_message` signature. Mirrors Solana's `Signable` trait, while
+/// keeping the raw `sign_message` primitive untouched underneath.
+#[uniffi::export]
+pub fn sign_document(
+    payload: Vec<u8>,
+    domain: String,
+    private_key: Vec<u8>,
+) -> Result<Vec<u8>, Ed25519Error> {
+    sign_message(domain_separated_message(&payload, &domain), private_key)
+}
+
+/// Verify a signature produced by `sign_document` for the given domain.
+#[uniffi::export]
+pub fn verify_document(
+    payload: Vec<u8>,
+    domain: String,
+    signature: Vec<u8>,
+    public_key: Vec<u8>,
+) -> Result<bool, Ed25519Error> {
+    verify_signature(
+        domain_separated_message(&payload, &domain),
+        signature,
+        public_key,
+    )
+}
+
 // UniFFI setup
-uniffi::setup_scaffolding!();
\ No newline at end of file
+uniffi::setup_scaffolding!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SLIP-0010 ed25519 test vector 1 (seed 000102030405060708090a0b0c0d0e0f),
+    // https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    const SEED_HEX: &str = "000102030405060708090a0b0c0d0e0f";
+    const MASTER_PRIV_HEX: &str =
+        "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7";
+    const MASTER_PUB_HEX: &str =
+        "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed";
+    const CHILD_0H_PRIV_HEX: &str =
+        "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3";
+    const CHILD_0H_PUB_HEX: &str =
+        "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c";
+
+    #[test]
+    fn derive_keypair_from_seed_matches_slip0010_master_node() {
+        let seed = hex::decode(SEED_HEX).unwrap();
+        let keypair = derive_keypair_from_seed(seed, "m".to_string()).unwrap();
+
+        assert_eq!(hex::encode(&keypair.private_key), MASTER_PRIV_HEX);
+        assert_eq!(hex::encode(&keypair.public_key), MASTER_PUB_HEX);
+    }
+
+    #[test]
+    fn derive_keypair_from_seed_matches_slip0010_hardened_child() {
+        let seed = hex::decode(SEED_HEX).unwrap();
+        let keypair = derive_keypair_from_seed(seed, "m/0'".to_string()).unwrap();
+
+        assert_eq!(hex::encode(&keypair.private_key), CHILD_0H_PRIV_HEX);
+        assert_eq!(hex::encode(&keypair.public_key), CHILD_0H_PUB_HEX);
+    }
+
+    #[test]
+    fn derive_keypair_from_seed_rejects_short_seed() {
+        let seed = vec![0u8; 8];
+        assert!(derive_keypair_from_seed(seed, "m".to_string()).is_err());
+    }
+
+    #[test]
+    fn derive_keypair_from_seed_rejects_malformed_path() {
+        let seed = hex::decode(SEED_HEX).unwrap();
+        assert!(derive_keypair_from_seed(seed, "44'/0'".to_string()).is_err());
+    }
+
+    #[test]
+    fn verify_batch_accepts_all_valid_signatures() {
+        let keypair_a = generate_keypair();
+        let keypair_b = generate_keypair();
+
+        let message_a = b"archive entry 1".to_vec();
+        let message_b = b"archive entry 2".to_vec();
+
+        let signature_a = sign_message(message_a.clone(), keypair_a.private_key.clone()).unwrap();
+        let signature_b = sign_message(message_b.clone(), keypair_b.private_key.clone()).unwrap();
+
+        let result = verify_batch(
+            vec![message_a, message_b],
+            vec![signature_a, signature_b],
+            vec![keypair_a.public_key.clone(), keypair_b.public_key.clone()],
+        )
+        .unwrap();
+
+        assert!(result);
+    }
+
+    #[test]
+    fn verify_batch_rejects_one_bad_signature() {
+        let keypair_a = generate_keypair();
+        let keypair_b = generate_keypair();
+
+        let message_a = b"archive entry 1".to_vec();
+        let message_b = b"archive entry 2".to_vec();
+
+        let signature_a = sign_message(message_a.clone(), keypair_a.private_key.clone()).unwrap();
+        // Sign message_b with the wrong key so the batch as a whole fails.
+        let bad_signature_b =
+            sign_message(message_b.clone(), keypair_a.private_key.clone()).unwrap();
+
+        let result = verify_batch(
+            vec![message_a, message_b],
+            vec![signature_a, bad_signature_b],
+            vec![keypair_a.public_key.clone(), keypair_b.public_key.clone()],
+        )
+        .unwrap();
+
+        assert!(!result);
+    }
+
+    #[test]
+    fn verify_batch_rejects_mismatched_lengths() {
+        let keypair = generate_keypair();
+        let message = b"archive entry".to_vec();
+        let signature = sign_message(message.clone(), keypair.private_key.clone()).unwrap();
+
+        let result = verify_batch(
+            vec![message],
+            vec![signature],
+            vec![keypair.public_key.clone(), keypair.public_key.clone()],
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn typed_ed25519_roundtrips_through_sign_and_verify() {
+        let keypair = generate_keypair_typed(KeyType::Ed25519);
+        let message = b"typed ed25519 message".to_vec();
+
+        let signature =
+            sign_message_typed(message.clone(), keypair.private_key.clone(), KeyType::Ed25519)
+                .unwrap();
+
+        let valid = verify_signature_typed(
+            message,
+            signature,
+            keypair.public_key.clone(),
+            KeyType::Ed25519,
+        )
+        .unwrap();
+
+        assert!(valid);
+    }
+
+    #[test]
+    fn typed_secp256k1_roundtrips_through_sign_and_verify() {
+        let keypair = generate_keypair_typed(KeyType::Secp256k1);
+        let message = b"typed secp256k1 message".to_vec();
+
+        let signature = sign_message_typed(
+            message.clone(),
+            keypair.private_key.clone(),
+            KeyType::Secp256k1,
+        )
+        .unwrap();
+
+        let valid = verify_signature_typed(
+            message,
+            signature,
+            keypair.public_key.clone(),
+            KeyType::Secp256k1,
+        )
+        .unwrap();
+
+        assert!(valid);
+    }
+
+    #[test]
+    fn typed_secp256k1_signature_does_not_verify_as_ed25519() {
+        let keypair = generate_keypair_typed(KeyType::Secp256k1);
+        let message = b"typed secp256k1 message".to_vec();
+
+        let signature = sign_message_typed(
+            message.clone(),
+            keypair.private_key.clone(),
+            KeyType::Secp256k1,
+        )
+        .unwrap();
+
+        // A 64-byte secp256k1 signature happens to be the same length as an
+        // ed25519 signature, but the 33-byte compressed secp256k1 public key
+        // is rejected by the ed25519 public key length check -- verifying it
+        // as ed25519 should error or report invalid, never panic.
+        let result =
+            verify_signature_typed(message, signature, keypair.public_key.clone(), KeyType::Ed25519);
+
+        match result {
+            Ok(valid) => assert!(!valid),
+            Err(_) => {}
+        }
+    }
+
+    #[test]
+    fn verify_signature_tagged_dispatches_ed25519() {
+        let keypair = generate_keypair_typed(KeyType::Ed25519);
+        let message = b"tagged ed25519 message".to_vec();
+
+        let signature =
+            sign_message_typed(message.clone(), keypair.private_key.clone(), KeyType::Ed25519)
+                .unwrap();
+
+        let valid =
+            verify_signature_tagged(message, signature, keypair.tagged_public_key()).unwrap();
+
+        assert!(valid);
+    }
+
+    #[test]
+    fn verify_signature_tagged_dispatches_secp256k1() {
+        let keypair = generate_keypair_typed(KeyType::Secp256k1);
+        let message = b"tagged secp256k1 message".to_vec();
+
+        let signature = sign_message_typed(
+            message.clone(),
+            keypair.private_key.clone(),
+            KeyType::Secp256k1,
+        )
+        .unwrap();
+
+        let valid =
+            verify_signature_tagged(message, signature, keypair.tagged_public_key()).unwrap();
+
+        assert!(valid);
+    }
+
+    #[test]
+    fn verify_signature_tagged_rejects_unknown_tag() {
+        let keypair = generate_keypair_typed(KeyType::Ed25519);
+        let message = b"tagged message".to_vec();
+
+        let signature =
+            sign_message_typed(message.clone(), keypair.private_key.clone(), KeyType::Ed25519)
+                .unwrap();
+
+        let mut tagged_public_key = keypair.tagged_public_key();
+        tagged_public_key[0] = 0xff;
+
+        let result = verify_signature_tagged(message, signature, tagged_public_key);
+
+        assert!(matches!(result, Err(Ed25519Error::UnsupportedKeyType { .. })));
+    }
+
+    #[test]
+    fn verify_signature_tagged_rejects_empty_tagged_public_key() {
+        let result = verify_signature_tagged(b"message".to_vec(), vec![0u8; 64], vec![]);
+
+        assert!(matches!(result, Err(Ed25519Error::UnsupportedKeyType { .. })));
+    }
+
+    #[test]
+    fn sign_document_roundtrips_within_the_same_domain() {
+        let keypair = generate_keypair();
+        let payload = b"archive manifest contents".to_vec();
+
+        let signature = sign_document(
+            payload.clone(),
+            "manifest-v1".to_string(),
+            keypair.private_key.clone(),
+        )
+        .unwrap();
+
+        let valid = verify_document(
+            payload,
+            "manifest-v1".to_string(),
+            signature,
+            keypair.public_key.clone(),
+        )
+        .unwrap();
+
+        assert!(valid);
+    }
+
+    #[test]
+    fn sign_document_does_not_verify_under_a_different_domain() {
+        let keypair = generate_keypair();
+        let payload = b"archive manifest contents".to_vec();
+
+        let signature = sign_document(
+            payload.clone(),
+            "manifest-v1".to_string(),
+            keypair.private_key.clone(),
+        )
+        .unwrap();
+
+        let valid = verify_document(
+            payload,
+            "entry-v1".to_string(),
+            signature,
+            keypair.public_key.clone(),
+        )
+        .unwrap();
+
+        assert!(!valid);
+    }
+
+    #[test]
+    fn sign_document_signature_does_not_verify_as_a_raw_message() {
+        let keypair = generate_keypair();
+        let payload = b"archive manifest contents".to_vec();
+
+        let signature = sign_document(
+            payload.clone(),
+            "manifest-v1".to_string(),
+            keypair.private_key.clone(),
+        )
+        .unwrap();
+
+        // The domain-tagged signature must not also validate against the
+        // bare, untagged payload via the raw primitive.
+        let valid = verify_signature(payload, signature, keypair.public_key.clone()).unwrap();
+
+        assert!(!valid);
+    }
+
+    #[test]
+    fn base58_string_roundtrips_a_keypair() {
+        let keypair = generate_keypair();
+        let encoded = keypair.to_base58_string();
+
+        let decoded = Ed25519KeyPair::from_base58_string(encoded).unwrap();
+
+        assert_eq!(decoded.private_key, keypair.private_key);
+        assert_eq!(decoded.public_key, keypair.public_key);
+    }
+
+    #[test]
+    fn from_base58_string_rejects_invalid_base58() {
+        // '0' is not part of the base58 alphabet.
+        let result = Ed25519KeyPair::from_base58_string("not-valid-base58-0".to_string());
+
+        assert!(matches!(result, Err(Ed25519Error::Base58DecodeFailed { .. })));
+    }
+
+    #[test]
+    fn from_base58_string_rejects_wrong_length_payload() {
+        let short_payload = bs58::encode(vec![1u8; 16]).into_string();
+
+        let result = Ed25519KeyPair::from_base58_string(short_payload);
+
+        assert!(matches!(result, Err(Ed25519Error::Base58DecodeFailed { .. })));
+    }
+
+    #[test]
+    fn keypair_file_roundtrips_through_write_and_read() {
+        let keypair = generate_keypair();
+        let path = std::env::temp_dir().join(format!(
+            "ed25519_uniffi_custom_test_keypair_{}.json",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap().to_string();
+
+        write_keypair_file(keypair.clone(), path.clone()).unwrap();
+        let read_back = read_keypair_file(path.clone()).unwrap();
+
+        assert_eq!(read_back.private_key, keypair.private_key);
+        assert_eq!(read_back.public_key, keypair.public_key);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn read_keypair_file_rejects_wrong_byte_count() {
+        let path = std::env::temp_dir().join(format!(
+            "ed25519_uniffi_custom_test_bad_keypair_{}.json",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap().to_string();
+
+        std::fs::write(&path, "[1,2,3]").unwrap();
+
+        let result = read_keypair_file(path.clone());
+
+        std::fs::remove_file(path).unwrap();
+
+        assert!(matches!(result, Err(Ed25519Error::KeypairFileError { .. })));
+    }
+}
\ No newline at end of file